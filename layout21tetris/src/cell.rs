@@ -12,7 +12,7 @@ use derive_more;
 // Local imports
 use crate::bbox::{BoundBox, HasBoundBox};
 use crate::coords::{PrimPitches, Xy};
-use crate::placement::{Place, Placeable};
+use crate::placement::{Align, Place, Placeable, RelativePlace, Side};
 use crate::raw::{Dir, LayoutError, LayoutResult};
 use crate::stack::{Assign, RelZ};
 use crate::utils::{Ptr, PtrList};
@@ -97,6 +97,225 @@ impl Layout {
         let name = net.into();
         NetHandle { name, parent: self }
     }
+    /// Resolve every relative placement in the layout to absolute coordinates.
+    ///
+    /// Walks every loc-bearing object — the layout's [Instance]s *and* the
+    /// [Placeable]s in `places` — resolving any whose `loc` is a relative
+    /// [Place] into absolute `Xy<PrimPitches>` computed from the resolved
+    /// `boundbox` of the referenced object (abutment: "place the left edge of
+    /// B against the right edge of A" becomes `A.loc.x + A.outline.xmax()`).
+    /// Resolution proceeds in dependency order, so chained relative placements
+    /// work; already-absolute placements are left untouched. Dependency cycles
+    /// are reported as an error naming the objects on the cycle, in order. Once
+    /// this returns `Ok`, every `loc.abs()` in the layout is guaranteed to
+    /// succeed, including those inside [Placeable]s such as [InstanceArray].
+    pub fn resolve_places(&mut self) -> LayoutResult {
+        // Resolution walks a single node space covering every loc-bearing
+        // object: node `i < ni` is `self.instances[i]`, node `ni + j` is
+        // `self.places[j]`. Placeables that carry no resolvable location count
+        // as nodes but start already-resolved.
+        let ni = self.instances.len();
+        let n = ni + self.places.len();
+        // Index nodes by name, and keep the names index-aligned for diagnostics.
+        let mut names: Vec<String> = Vec::with_capacity(n);
+        let mut name_to_idx = std::collections::HashMap::with_capacity(n);
+        for node in 0..n {
+            let name = self.node_name(node, ni)?;
+            if let Some(name) = &name {
+                name_to_idx.insert(name.clone(), node);
+            }
+            names.push(name.unwrap_or_else(|| format!("<place#{}>", node - ni)));
+        }
+        // Anything not relative begins resolved; relative nodes await their refs.
+        let mut resolved = vec![false; n];
+        for node in 0..n {
+            resolved[node] = self.node_rel(node, ni)?.is_none();
+        }
+        let mut remaining = resolved.iter().filter(|done| !**done).count();
+        // Repeatedly resolve any node whose reference is already absolute, until
+        // no progress is made. No progress with nodes left means a cycle.
+        while remaining > 0 {
+            let mut progressed = false;
+            for node in 0..n {
+                if resolved[node] {
+                    continue;
+                }
+                let rel = match self.node_rel(node, ni)? {
+                    Some(rel) => rel,
+                    None => continue,
+                };
+                let to = name_to_idx.get(&rel.to).copied().ok_or_else(|| {
+                    LayoutError::msg(format!(
+                        "Relative placement of `{}` references unknown object `{}`",
+                        names[node], rel.to
+                    ))
+                })?;
+                // Reference must be resolved before we can place this one.
+                if !resolved[to] {
+                    continue;
+                }
+                let abs = self.resolve_relative(node, &rel, to, ni)?;
+                self.node_set_abs(node, ni, abs)?;
+                resolved[node] = true;
+                remaining -= 1;
+                progressed = true;
+            }
+            if !progressed {
+                return Err(self.describe_cycle(&resolved, &names, &name_to_idx, ni)?);
+            }
+        }
+        Ok(())
+    }
+    /// Name of node `node`, or `None` for a placeable that carries no location.
+    fn node_name(&self, node: usize, ni: usize) -> LayoutResult<Option<String>> {
+        if node < ni {
+            Ok(Some(self.instances[node].read()?.inst_name.clone()))
+        } else {
+            Ok(match &self.places[node - ni] {
+                Placeable::Instance(p) => Some(p.read()?.inst_name.clone()),
+                Placeable::InstanceArray(p) => Some(p.read()?.inst_name.clone()),
+                _ => None,
+            })
+        }
+    }
+    /// Relative placement of node `node`, or `None` if it is absolute or carries
+    /// no location.
+    fn node_rel(&self, node: usize, ni: usize) -> LayoutResult<Option<RelativePlace>> {
+        let rel_of = |loc: &Place<Xy<PrimPitches>>| match loc {
+            Place::Abs(_) => None,
+            Place::Rel(rel) => Some(rel.clone()),
+        };
+        if node < ni {
+            Ok(rel_of(&self.instances[node].read()?.loc))
+        } else {
+            Ok(match &self.places[node - ni] {
+                Placeable::Instance(p) => rel_of(&p.read()?.loc),
+                Placeable::InstanceArray(p) => rel_of(&p.read()?.loc),
+                _ => None,
+            })
+        }
+    }
+    /// Assign node `node`'s resolved absolute location.
+    fn node_set_abs(&self, node: usize, ni: usize, abs: Xy<PrimPitches>) -> LayoutResult {
+        if node < ni {
+            self.instances[node].write()?.loc = Place::Abs(abs);
+        } else {
+            match &self.places[node - ni] {
+                Placeable::Instance(p) => p.write()?.loc = Place::Abs(abs),
+                Placeable::InstanceArray(p) => p.write()?.loc = Place::Abs(abs),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+    /// Resolved bounding box of node `node`, used as a relative-placement anchor.
+    fn node_boundbox(&self, node: usize, ni: usize) -> LayoutResult<BoundBox<PrimPitches>> {
+        if node < ni {
+            return self.instances[node].read()?.boundbox();
+        }
+        match &self.places[node - ni] {
+            Placeable::Instance(p) => p.read()?.boundbox(),
+            Placeable::InstanceArray(p) => p.read()?.boundbox(),
+            _ => Err(LayoutError::msg(
+                "Relative placement anchored to a non-geometric placeable".to_string(),
+            )),
+        }
+    }
+    /// Footprint size of node `node`, honoring orientation. Unlike
+    /// [node_boundbox](Layout::node_boundbox) this needs no resolved `loc`, so
+    /// it can size an object that is itself still being placed.
+    fn node_size(&self, node: usize, ni: usize) -> LayoutResult<Xy<PrimPitches>> {
+        if node < ni {
+            return self.instances[node].read()?.boundbox_size();
+        }
+        match &self.places[node - ni] {
+            Placeable::Instance(p) => p.read()?.boundbox_size(),
+            Placeable::InstanceArray(p) => p.read()?.boundbox_size(),
+            _ => Ok(Xy::new(PrimPitches::new(0), PrimPitches::new(0))),
+        }
+    }
+    /// Compute the absolute origin of node `node`, whose relative placement
+    /// `rel` references the already-resolved node `to`. The point computed is
+    /// the min corner of a box of the placed object's size which abuts the
+    /// reference's `boundbox` on `rel.side`, offset by `rel.sep`, and is aligned
+    /// on the orthogonal axis per `rel.align` — so all four sides, three
+    /// alignments, and a separation gap are covered. That point is returned
+    /// unchanged as the object's origin, so the object's own `boundbox` abuts
+    /// the reference only where its origin is its min corner (`R0` and
+    /// `MirrorR90` orientations, and arrays with non-negative pitches); other
+    /// placements are not shifted to account for where their origin lies.
+    fn resolve_relative(
+        &self,
+        node: usize,
+        rel: &RelativePlace,
+        to: usize,
+        ni: usize,
+    ) -> LayoutResult<Xy<PrimPitches>> {
+        let anchor = self.node_boundbox(to, ni)?;
+        let size = self.node_size(node, ni)?;
+        let anchor_w = anchor.p1.x - anchor.p0.x;
+        let anchor_h = anchor.p1.y - anchor.p0.y;
+        // Position the placed object's `own` extent within `[amin, amin+span]`.
+        let align = |amin: PrimPitches, span: PrimPitches, own: PrimPitches| match rel.align {
+            Align::Min => amin,
+            Align::Max => amin + span - own,
+            Align::Center => amin + (span - own) / 2,
+        };
+        Ok(match rel.side {
+            Side::Right => Xy::new(anchor.p1.x + rel.sep, align(anchor.p0.y, anchor_h, size.y)),
+            Side::Left => Xy::new(
+                anchor.p0.x - rel.sep - size.x,
+                align(anchor.p0.y, anchor_h, size.y),
+            ),
+            Side::Top => Xy::new(align(anchor.p0.x, anchor_w, size.x), anchor.p1.y + rel.sep),
+            Side::Bottom => Xy::new(
+                align(anchor.p0.x, anchor_w, size.x),
+                anchor.p0.y - rel.sep - size.y,
+            ),
+        })
+    }
+    /// Build a dependency-cycle error reporting the *actual* cycle path. Follows
+    /// relative references from an unresolved node until one repeats; the names
+    /// between the two visits are the cycle, in order.
+    fn describe_cycle(
+        &self,
+        resolved: &[bool],
+        names: &[String],
+        name_to_idx: &std::collections::HashMap<String, usize>,
+        ni: usize,
+    ) -> LayoutResult<LayoutError> {
+        let start = resolved.iter().position(|done| !*done).unwrap_or(0);
+        let mut order = Vec::new();
+        let mut seen = std::collections::HashMap::new();
+        let mut cur = start;
+        loop {
+            if let Some(&pos) = seen.get(&cur) {
+                let chain: Vec<&str> = order[pos..]
+                    .iter()
+                    .map(|&node: &usize| names[node].as_str())
+                    .collect();
+                return Ok(LayoutError::msg(format!(
+                    "Relative-placement dependency cycle: {} -> {}",
+                    chain.join(" -> "),
+                    names[cur]
+                )));
+            }
+            seen.insert(cur, order.len());
+            order.push(cur);
+            cur = match self.node_rel(cur, ni)? {
+                Some(rel) => match name_to_idx.get(&rel.to) {
+                    Some(&next) => next,
+                    None => break,
+                },
+                None => break,
+            };
+        }
+        // Fallback: every unresolved node ultimately depends on a cycle, so this
+        // is only reached if the graph shape changed underfoot.
+        Ok(LayoutError::msg(
+            "Relative-placement dependency cycle detected".to_string(),
+        ))
+    }
 }
 /// A short-term handle for chaining multiple assignments to a net
 /// Typically used as: `mycell.net("name").at(/* args */).at(/* more args */)`
@@ -233,6 +452,104 @@ impl Cell {
             Ok(Some(metals - 1))
         }
     }
+    /// Consistency-check the cell's views and promote it to a [ValidCell].
+    ///
+    /// Runs the pass that [outline](Cell::outline) and [metals](Cell::metals)
+    /// only promise in their FIXMEs: confirm at least one geometry-bearing view
+    /// (abstract, layout, or raw) exists, and that every such view present
+    /// reports the same `outline` and `metals`. On success
+    /// the shared values are cached so the [ValidCell] getters are infallible.
+    pub fn validate(self) -> LayoutResult<ValidCell> {
+        // Gather `(view-name, outline, metals)` for each geometry-bearing view.
+        // The `interface` view carries no geometry and is not checked.
+        let mut views: Vec<(&str, &outline::Outline, usize)> = Vec::new();
+        if let Some(ref x) = self.abs {
+            views.push(("abstract", &x.outline, x.metals));
+        }
+        if let Some(ref x) = self.layout {
+            views.push(("layout", &x.outline, x.metals));
+        }
+        if let Some(ref x) = self.raw {
+            views.push(("raw", &x.outline, x.metals));
+        }
+        let &(ref_view, ref_outline, ref_metals) = match views.first() {
+            Some(first) => first,
+            None => return Err(LayoutError::Validation),
+        };
+        for &(view, outline, metals) in views.iter().skip(1) {
+            if outline != ref_outline {
+                return Err(LayoutError::msg(format!(
+                    "Cell {}: `outline` disagrees between views `{}` and `{}`",
+                    self.name, ref_view, view
+                )));
+            }
+            if metals != ref_metals {
+                return Err(LayoutError::msg(format!(
+                    "Cell {}: `metals` disagrees between views `{}` and `{}`",
+                    self.name, ref_view, view
+                )));
+            }
+        }
+        let outline = ref_outline.clone();
+        Ok(ValidCell {
+            cell: self,
+            outline,
+            metals: ref_metals,
+        })
+    }
+}
+/// # Validated [Cell]
+///
+/// A [Cell] which has passed [Cell::validate]: it has at least one abstract,
+/// layout, or raw view, and every such view present agrees on its `outline`
+/// and `metals`. Because those
+/// checks run once at construction, the geometric queries which are fallible
+/// on a raw [Cell] — [outline](ValidCell::outline), [metals](ValidCell::metals),
+/// [top_metal](ValidCell::top_metal), and [boundbox_size](ValidCell::boundbox_size) —
+/// become plain, infallible getters. Downstream placement code can query
+/// geometry without threading `?` through every access.
+#[derive(Debug, Clone)]
+pub struct ValidCell {
+    /// The validated [Cell].
+    /// Private so the cached `outline`/`metals` cannot drift out of agreement
+    /// with the views after validation; read it via [cell](ValidCell::cell) or
+    /// reclaim it with [into_inner](ValidCell::into_inner).
+    cell: Cell,
+    /// Consistency-checked outline, shared by every present view
+    outline: outline::Outline,
+    /// Consistency-checked metal-layer count, shared by every present view
+    metals: usize,
+}
+impl ValidCell {
+    /// Borrow the validated [Cell].
+    pub fn cell(&self) -> &Cell {
+        &self.cell
+    }
+    /// Consume, returning the inner [Cell]. Mutating it forfeits the validation
+    /// guarantee, so the [ValidCell] wrapper is surrendered along with it.
+    pub fn into_inner(self) -> Cell {
+        self.cell
+    }
+    /// The cell's outline. Infallible: validated at [Cell::validate].
+    pub fn outline(&self) -> &outline::Outline {
+        &self.outline
+    }
+    /// Number of metal layers used. Infallible: validated at [Cell::validate].
+    pub fn metals(&self) -> usize {
+        self.metals
+    }
+    /// Top metal layer number, or `None` if no metal layers are used.
+    pub fn top_metal(&self) -> Option<usize> {
+        if self.metals == 0 {
+            None
+        } else {
+            Some(self.metals - 1)
+        }
+    }
+    /// Size of the cell's rectangular `boundbox`.
+    pub fn boundbox_size(&self) -> Xy<PrimPitches> {
+        Xy::new(self.outline.xmax(), self.outline.ymax())
+    }
 }
 impl From<CellView> for Cell {
     fn from(src: CellView) -> Self {
@@ -285,6 +602,78 @@ impl From<RawLayoutPtr> for Cell {
     }
 }
 
+/// # Instance Orientation
+///
+/// The eight elements of the dihedral group `D4`: each of the four 90-degree
+/// rotations `R{0,90,180,270}`, optionally mirrored (reflected across the
+/// x-axis before rotating). Supersedes the former `reflect_horiz`/`reflect_vert`
+/// pair, which could only express `R0`, `R180`, and the two axis mirrors —
+/// never the 90/270-degree rotations that abutting cells on rotated rows need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    R0,
+    R90,
+    R180,
+    R270,
+    MirrorR0,
+    MirrorR90,
+    MirrorR180,
+    MirrorR270,
+}
+impl Orientation {
+    /// Build from the legacy `reflect_horiz`/`reflect_vert` pair, preserving
+    /// the four orientations that pair could express.
+    pub fn from_reflect(reflect_horiz: bool, reflect_vert: bool) -> Self {
+        match (reflect_horiz, reflect_vert) {
+            (false, false) => Self::R0,
+            (true, true) => Self::R180,
+            (false, true) => Self::MirrorR0,
+            (true, false) => Self::MirrorR180,
+        }
+    }
+    /// Whether this orientation swaps the x and y extents, i.e. is a 90- or
+    /// 270-degree rotation.
+    pub fn swaps_axes(&self) -> bool {
+        matches!(
+            self,
+            Self::R90 | Self::R270 | Self::MirrorR90 | Self::MirrorR270
+        )
+    }
+    /// The quadrant a placed cell occupies relative to its origin, as
+    /// `(extends_neg_x, extends_neg_y)`. Mapping the local first-quadrant box
+    /// `[0,W]×[0,H]` through each transform (mirror-across-x then rotate) lands
+    /// it in one of the four quadrants, each shared by one axis-swapping and one
+    /// non-swapping orientation; combined with [swaps_axes](Orientation::swaps_axes)
+    /// this gives all eight orientations distinct bounding boxes for any
+    /// non-square cell.
+    pub fn quadrant(&self) -> (bool, bool) {
+        match self {
+            Self::R0 => (false, false),
+            Self::R90 => (true, false),
+            Self::R180 => (true, true),
+            Self::R270 => (false, true),
+            Self::MirrorR0 => (false, true),
+            Self::MirrorR90 => (false, false),
+            Self::MirrorR180 => (true, false),
+            Self::MirrorR270 => (true, true),
+        }
+    }
+    /// Legacy-style reflection query along `dir`. Reports the axis-aligned
+    /// mirrors, and `R180` as reflected along both axes; the diagonal
+    /// reflections (`MirrorR90`/`MirrorR270`) and the rotations `R0`, `R90`,
+    /// and `R270` have no single-axis reflection and report `false`.
+    pub fn reflected(&self, dir: Dir) -> bool {
+        match dir {
+            Dir::Horiz => matches!(self, Self::R180 | Self::MirrorR180),
+            Dir::Vert => matches!(self, Self::R180 | Self::MirrorR0),
+        }
+    }
+}
+impl Default for Orientation {
+    fn default() -> Self {
+        Self::R0
+    }
+}
 /// Instance of another Cell
 #[derive(Debug, Clone)]
 pub struct Instance {
@@ -293,26 +682,157 @@ pub struct Instance {
     /// Cell Definition Reference
     pub cell: Ptr<Cell>,
     /// Location of the Instance origin
-    /// This origin-position holds regardless of either `reflect` field.
+    /// This origin-position holds regardless of `orientation`.
     /// If specified in absolute coordinates, location-units are [PrimPitches].
     pub loc: Place<Xy<PrimPitches>>,
-    /// Horizontal Reflection
-    pub reflect_horiz: bool,
-    /// Vertical Reflection
-    pub reflect_vert: bool,
+    /// Placement orientation, one of the eight dihedral transforms
+    pub orientation: Orientation,
 }
 impl Instance {
     /// Boolean indication of whether this Instance is reflected in direction `dir`
     pub fn reflected(&self, dir: Dir) -> bool {
-        match dir {
-            Dir::Horiz => self.reflect_horiz,
-            Dir::Vert => self.reflect_vert,
+        self.orientation.reflected(dir)
+    }
+    /// Size of the Instance's rectangular `boundbox`, i.e. the zero-origin
+    /// `boundbox` of its `cell`. For 90/270-degree rotations the cell's x and y
+    /// dimensions are swapped.
+    pub fn boundbox_size(&self) -> LayoutResult<Xy<PrimPitches>> {
+        let cell = self.cell.read()?;
+        let size = cell.boundbox_size()?;
+        Ok(if self.orientation.swaps_axes() {
+            Xy::new(size.y, size.x)
+        } else {
+            size
+        })
+    }
+}
+/// # Arrayed Instance
+///
+/// A regular `rows`×`cols` grid of instances of a single [Cell], placed on a
+/// fixed `(xpitch, ypitch)` stride. Avoids materializing every [Instance] by
+/// hand; call [expand](InstanceArray::expand) to produce the individual
+/// [Instance]s, or [boundbox](HasBoundBox::boundbox) to get the array footprint
+/// straight from the stride-and-count arithmetic without expanding.
+///
+/// Stored in a [Layout] via the `Placeable::InstanceArray` variant, so an array
+/// is a first-class member of `Layout.places`.
+#[derive(Debug, Clone)]
+pub struct InstanceArray {
+    /// Instance-Array Name
+    pub inst_name: String,
+    /// Cell Definition Reference
+    pub cell: Ptr<Cell>,
+    /// Location of the array origin, i.e. of element `(0, 0)`
+    pub loc: Place<Xy<PrimPitches>>,
+    /// Number of rows, arrayed along `y`
+    pub rows: usize,
+    /// Number of columns, arrayed along `x`
+    pub cols: usize,
+    /// Column-to-column pitch, along `x`
+    pub xpitch: PrimPitches,
+    /// Row-to-row pitch, along `y`
+    pub ypitch: PrimPitches,
+    /// Placement orientation, applied to every element
+    pub orientation: Orientation,
+}
+impl InstanceArray {
+    /// Expand into the individual [Instance]s, element `(i, j)` landing at
+    /// `loc + (i*xpitch, j*ypitch)` for `i in 0..cols`, `j in 0..rows`.
+    /// `rows` or `cols` of zero yields an empty expansion.
+    /// The array `loc` must be resolved to absolute coordinates, or this fails.
+    pub fn expand(&self) -> LayoutResult<Vec<Instance>> {
+        let base = self.loc.abs()?;
+        let mut insts = Vec::with_capacity(self.rows * self.cols);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                let loc = Xy::new(base.x + self.xpitch * i, base.y + self.ypitch * j);
+                insts.push(Instance {
+                    inst_name: format!("{}[{},{}]", self.inst_name, i, j),
+                    cell: self.cell.clone(),
+                    loc: Place::Abs(loc),
+                    orientation: self.orientation,
+                });
+            }
         }
+        Ok(insts)
     }
-    /// Size of the Instance's rectangular `boundbox`, i.e. the zero-origin `boundbox` of its `cell`.
+    /// Size of the array's rectangular footprint, without expanding or needing a
+    /// resolved `loc`. Swaps the cell extents for rotated elements; an empty
+    /// array has zero size.
     pub fn boundbox_size(&self) -> LayoutResult<Xy<PrimPitches>> {
+        if self.rows == 0 || self.cols == 0 {
+            return Ok(Xy::new(PrimPitches::new(0), PrimPitches::new(0)));
+        }
+        let cell = self.cell.read()?;
+        let outline = cell.outline()?;
+        let (exx, exy) = if self.orientation.swaps_axes() {
+            (outline.ymax(), outline.xmax())
+        } else {
+            (outline.xmax(), outline.ymax())
+        };
+        Ok(Xy::new(
+            span(self.xpitch, self.cols) + exx,
+            span(self.ypitch, self.rows) + exy,
+        ))
+    }
+}
+/// Order a pair into `(min, max)`, so a span built from it is valid regardless
+/// of the sign of the stride that produced it.
+fn order<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+/// Absolute distance covered by `n` elements on `pitch`, i.e. the magnitude of
+/// `(n-1)*pitch`. Zero for an empty or single-element run.
+fn span(pitch: PrimPitches, n: usize) -> PrimPitches {
+    if n <= 1 {
+        return PrimPitches::new(0);
+    }
+    let far = pitch * (n - 1);
+    let (lo, hi) = order(PrimPitches::new(0), far);
+    hi - lo
+}
+impl HasBoundBox for InstanceArray {
+    type Units = PrimPitches;
+    type Error = LayoutError;
+    /// Retrieve the array's bounding rectangle, specified in [PrimPitches],
+    /// directly from the stride-and-count arithmetic.
+    /// Array location must be resolved to absolute coordinates, or this fails.
+    fn boundbox(&self) -> LayoutResult<BoundBox<PrimPitches>> {
+        let loc = self.loc.abs()?;
+        // An empty array has a degenerate, zero-size boundbox at its origin.
+        if self.rows == 0 || self.cols == 0 {
+            return Ok(BoundBox::new(loc, loc));
+        }
         let cell = self.cell.read()?;
-        cell.boundbox_size()
+        let outline = cell.outline()?;
+        // Element `(i, j)` sits at `loc + (i*xpitch, j*ypitch)` and, exactly as
+        // in `expand`, carries the array `orientation` about *its own* origin.
+        // So the array spans the range of element origins (ordered, to tolerate
+        // negative pitches) grown by one element extent in the orientation's
+        // occupied quadrant, with x/y extents swapped for rotated elements.
+        let (exx, exy) = if self.orientation.swaps_axes() {
+            (outline.ymax(), outline.xmax())
+        } else {
+            (outline.xmax(), outline.ymax())
+        };
+        let (neg_x, neg_y) = self.orientation.quadrant();
+        let farx = self.xpitch * (self.cols - 1);
+        let fary = self.ypitch * (self.rows - 1);
+        let (minx, maxx) = order(loc.x, loc.x + farx);
+        let (miny, maxy) = order(loc.y, loc.y + fary);
+        let (x0, x1) = match neg_x {
+            false => (minx, maxx + exx),
+            true => (minx - exx, maxx),
+        };
+        let (y0, y1) = match neg_y {
+            false => (miny, maxy + exy),
+            true => (miny - exy, maxy),
+        };
+        Ok(BoundBox::new(Xy::new(x0, y0), Xy::new(x1, y1)))
     }
 }
 impl std::fmt::Display for Instance {
@@ -337,14 +857,259 @@ impl HasBoundBox for Instance {
         let loc = self.loc.abs()?;
         let cell = self.cell.read()?;
         let outline = cell.outline()?;
-        let (x0, x1) = match self.reflect_horiz {
-            false => (loc.x, loc.x + outline.xmax()),
-            true => (loc.x - outline.xmax(), loc.x),
+        // For 90/270-degree rotations the x and y extents are swapped,
+        // and the occupied quadrant is set per-orientation so the four
+        // rotations (and their mirrors) yield four distinct boxes.
+        let (xmax, ymax) = if self.orientation.swaps_axes() {
+            (outline.ymax(), outline.xmax())
+        } else {
+            (outline.xmax(), outline.ymax())
         };
-        let (y0, y1) = match self.reflect_vert {
-            false => (loc.y, loc.y + outline.ymax()),
-            true => (loc.y - outline.ymax(), loc.y),
+        let (neg_x, neg_y) = self.orientation.quadrant();
+        let (x0, x1) = match neg_x {
+            false => (loc.x, loc.x + xmax),
+            true => (loc.x - xmax, loc.x),
+        };
+        let (y0, y1) = match neg_y {
+            false => (loc.y, loc.y + ymax),
+            true => (loc.y - ymax, loc.y),
         };
         Ok(BoundBox::new(Xy::new(x0, y0), Xy::new(x1, y1)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A rectangular [outline::Outline] of `x` by `y` primitive pitches.
+    fn outline(x: usize, y: usize) -> outline::Outline {
+        outline::Outline::rect(PrimPitches::new(x as isize), PrimPitches::new(y as isize)).unwrap()
+    }
+    /// A [Cell] carrying a single [Layout] view of the given geometry.
+    fn layout_cell(name: &str, metals: usize, x: usize, y: usize) -> Cell {
+        let mut cell = Cell::new(name);
+        cell.layout = Some(Layout::new(name, metals, outline(x, y)));
+        cell
+    }
+    /// A shared-pointer to an `x`-by-`y` [Cell].
+    fn cell_ptr(name: &str, x: usize, y: usize) -> Ptr<Cell> {
+        Ptr::new(layout_cell(name, 1, x, y))
+    }
+    /// A [PrimPitches] coordinate.
+    fn pp(n: isize) -> PrimPitches {
+        PrimPitches::new(n)
+    }
+    /// An absolute location at `(x, y)` primitive pitches.
+    fn at(x: isize, y: isize) -> Place<Xy<PrimPitches>> {
+        Place::Abs(Xy::new(pp(x), pp(y)))
+    }
+
+    #[test]
+    fn validate_requires_a_view() {
+        // A viewless cell cannot be validated.
+        assert!(Cell::new("empty").validate().is_err());
+    }
+    #[test]
+    fn validate_single_view_is_total() {
+        let valid = layout_cell("c", 3, 10, 20).validate().unwrap();
+        assert_eq!(valid.metals(), 3);
+        assert_eq!(valid.top_metal(), Some(2));
+        assert_eq!(valid.boundbox_size(), Xy::new(PrimPitches::new(10), PrimPitches::new(20)));
+    }
+    #[test]
+    fn validate_zero_metals_has_no_top_metal() {
+        let valid = layout_cell("c", 0, 4, 4).validate().unwrap();
+        assert_eq!(valid.top_metal(), None);
+    }
+    #[test]
+    fn validate_reports_conflicting_views() {
+        // Abstract and layout views disagree on `outline`.
+        let mut cell = layout_cell("c", 2, 10, 20);
+        cell.abs = Some(abs::Abstract::new("c", 2, outline(11, 20)));
+        let err = cell.validate().unwrap_err();
+        let msg = format!("{:?}", err);
+        assert!(msg.contains("abstract") && msg.contains("layout"));
+        assert!(msg.contains("outline"));
+    }
+
+    #[test]
+    fn orientation_from_reflect_round_trips() {
+        for (h, v) in [(false, false), (true, false), (false, true), (true, true)] {
+            let o = Orientation::from_reflect(h, v);
+            assert_eq!(o.reflected(Dir::Horiz), h);
+            assert_eq!(o.reflected(Dir::Vert), v);
+            assert!(!o.swaps_axes());
+        }
+    }
+    #[test]
+    fn orientation_rotations_swap_axes() {
+        for o in [
+            Orientation::R90,
+            Orientation::R270,
+            Orientation::MirrorR90,
+            Orientation::MirrorR270,
+        ] {
+            assert!(o.swaps_axes());
+        }
+        for o in [
+            Orientation::R0,
+            Orientation::R180,
+            Orientation::MirrorR0,
+            Orientation::MirrorR180,
+        ] {
+            assert!(!o.swaps_axes());
+        }
+    }
+    #[test]
+    fn rotations_occupy_distinct_quadrants() {
+        // The four pure rotations of a 10x20 cell at the origin must yield four
+        // distinct boxes, each in the quadrant it physically occupies.
+        let cell = cell_ptr("c", 10, 20);
+        let bbox = |o| {
+            Instance {
+                inst_name: "i".into(),
+                cell: cell.clone(),
+                loc: at(0, 0),
+                orientation: o,
+            }
+            .boundbox()
+            .unwrap()
+        };
+        let r0 = bbox(Orientation::R0);
+        let r90 = bbox(Orientation::R90);
+        let r180 = bbox(Orientation::R180);
+        let r270 = bbox(Orientation::R270);
+        // R0: +x/+y, unswapped.
+        assert_eq!((r0.p0, r0.p1), (Xy::new(pp(0), pp(0)), Xy::new(pp(10), pp(20))));
+        // R90: -x/+y, swapped extents.
+        assert_eq!((r90.p0, r90.p1), (Xy::new(pp(-20), pp(0)), Xy::new(pp(0), pp(10))));
+        // R180: -x/-y, unswapped.
+        assert_eq!((r180.p0, r180.p1), (Xy::new(pp(-10), pp(-20)), Xy::new(pp(0), pp(0))));
+        // R270: +x/-y, swapped extents.
+        assert_eq!((r270.p0, r270.p1), (Xy::new(pp(0), pp(-10)), Xy::new(pp(20), pp(0))));
+        // All four distinct.
+        for (a, b) in [
+            (&r0, &r90),
+            (&r0, &r180),
+            (&r0, &r270),
+            (&r90, &r180),
+            (&r90, &r270),
+            (&r180, &r270),
+        ] {
+            assert_ne!((a.p0, a.p1), (b.p0, b.p1));
+        }
+    }
+    #[test]
+    fn rotated_boundbox_size_is_swapped() {
+        let cell = cell_ptr("c", 10, 20);
+        let inst = Instance {
+            inst_name: "i".into(),
+            cell,
+            loc: at(0, 0),
+            orientation: Orientation::R90,
+        };
+        assert_eq!(inst.boundbox_size().unwrap(), Xy::new(pp(20), pp(10)));
+    }
+
+    /// An `rows`x`cols` array of a 10x20 cell, origin at `(0,0)`, `R0`.
+    fn array(rows: usize, cols: usize, xpitch: isize, ypitch: isize) -> InstanceArray {
+        InstanceArray {
+            inst_name: "arr".into(),
+            cell: cell_ptr("c", 10, 20),
+            loc: at(0, 0),
+            rows,
+            cols,
+            xpitch: pp(xpitch),
+            ypitch: pp(ypitch),
+            orientation: Orientation::R0,
+        }
+    }
+
+    #[test]
+    fn array_expands_column_major_grid() {
+        let insts = array(2, 3, 100, 200).expand().unwrap();
+        assert_eq!(insts.len(), 6);
+        // Element (i=2, j=1) lands at (2*100, 1*200).
+        let last = insts.last().unwrap();
+        assert_eq!(last.loc.abs().unwrap(), Xy::new(pp(200), pp(200)));
+    }
+    #[test]
+    fn array_zero_dim_is_empty_and_degenerate() {
+        assert!(array(0, 3, 100, 200).expand().unwrap().is_empty());
+        assert!(array(2, 0, 100, 200).expand().unwrap().is_empty());
+        let bbox = array(0, 3, 100, 200).boundbox().unwrap();
+        assert_eq!(bbox.p0, bbox.p1);
+    }
+    #[test]
+    fn array_boundbox_matches_positive_pitch() {
+        // 2 rows x 3 cols, pitch (100,200), cell 10x20.
+        let bbox = array(2, 3, 100, 200).boundbox().unwrap();
+        assert_eq!(bbox.p0, Xy::new(pp(0), pp(0)));
+        assert_eq!(bbox.p1, Xy::new(pp(2 * 100 + 10), pp(1 * 200 + 20)));
+    }
+    #[test]
+    fn array_boundbox_orders_negative_pitch() {
+        // Negative-going pitch still yields a correctly ordered box that
+        // contains every expanded element.
+        let arr = array(2, 3, -100, -200);
+        let bbox = arr.boundbox().unwrap();
+        assert!(bbox.p0.x <= bbox.p1.x && bbox.p0.y <= bbox.p1.y);
+        for inst in arr.expand().unwrap() {
+            let o = inst.loc.abs().unwrap();
+            assert!(bbox.p0.x <= o.x && o.x <= bbox.p1.x);
+            assert!(bbox.p0.y <= o.y && o.y <= bbox.p1.y);
+        }
+    }
+
+    /// A relative placement abutting the right edge of instance `to`.
+    fn right_of(to: &str) -> Place<Xy<PrimPitches>> {
+        Place::Rel(RelativePlace {
+            to: to.into(),
+            side: Side::Right,
+            align: Align::Min,
+            sep: pp(0),
+        })
+    }
+    /// An [Instance] of a 10x20 cell at `loc`.
+    fn inst(name: &str, cell: &Ptr<Cell>, loc: Place<Xy<PrimPitches>>) -> Ptr<Instance> {
+        Ptr::new(Instance {
+            inst_name: name.into(),
+            cell: cell.clone(),
+            loc,
+            orientation: Orientation::R0,
+        })
+    }
+
+    #[test]
+    fn resolve_places_chains_relative_placements() {
+        // A (absolute) <- B (right of A) <- C (right of B), declared out of order.
+        let cell = cell_ptr("c", 10, 20);
+        let mut layout = Layout::new("top", 1, outline(100, 100));
+        layout.instances.add(inst("C", &cell, right_of("B")));
+        layout.instances.add(inst("A", &cell, at(0, 0)));
+        layout.instances.add(inst("B", &cell, right_of("A")));
+        layout.resolve_places().unwrap();
+        // B abuts A's right edge at x=10, C abuts B's right edge at x=20.
+        let locs: Vec<_> = (0..3)
+            .map(|i| {
+                let inst = layout.instances[i].read().unwrap();
+                (inst.inst_name.clone(), inst.loc.abs().unwrap())
+            })
+            .collect();
+        assert_eq!(locs[0], ("C".into(), Xy::new(pp(20), pp(0))));
+        assert_eq!(locs[1], ("A".into(), Xy::new(pp(0), pp(0))));
+        assert_eq!(locs[2], ("B".into(), Xy::new(pp(10), pp(0))));
+    }
+    #[test]
+    fn resolve_places_reports_cycle_chain() {
+        let cell = cell_ptr("c", 10, 20);
+        let mut layout = Layout::new("top", 1, outline(100, 100));
+        layout.instances.add(inst("A", &cell, right_of("B")));
+        layout.instances.add(inst("B", &cell, right_of("A")));
+        let err = layout.resolve_places().unwrap_err();
+        let msg = format!("{:?}", err);
+        assert!(msg.contains("cycle"));
+        assert!(msg.contains("A") && msg.contains("B"));
+    }
+}